@@ -0,0 +1,113 @@
+//! The runtime [`Config`] mirror of `webrtc::AudioProcessing::Config`. Only the
+//! knobs callers typically reach for are surfaced; everything else keeps the
+//! WebRTC default when the config is applied.
+
+use webrtc_audio_processing_sys as ffi;
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// Config used to initialize and re-configure the processing pipeline.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Config {
+    /// High-pass filter applied to the capture stream before AEC3.
+    pub high_pass_filter: Option<HighPassFilter>,
+    /// Acoustic echo cancellation (AEC3).
+    pub echo_canceller: Option<EchoCanceller>,
+    /// Single-channel noise suppression.
+    pub noise_suppression: Option<NoiseSuppression>,
+    /// Adaptive/fixed gain control.
+    pub gain_controller: Option<GainController>,
+}
+
+/// High-pass filter stage.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct HighPassFilter {
+    pub enabled: bool,
+}
+
+/// Acoustic echo cancellation. `mobile_mode` selects the lighter AECM path used
+/// on low-power devices instead of full AEC3.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct EchoCanceller {
+    pub enabled: bool,
+    pub mobile_mode: bool,
+}
+
+/// Noise suppression aggressiveness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+pub enum NoiseSuppressionLevel {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+}
+
+impl Default for NoiseSuppressionLevel {
+    fn default() -> Self {
+        NoiseSuppressionLevel::Moderate
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct NoiseSuppression {
+    pub enabled: bool,
+    pub level: NoiseSuppressionLevel,
+}
+
+/// Automatic gain control. Wraps the AGC1 path, which is the default across
+/// desktop WebRTC deployments.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct GainController {
+    pub enabled: bool,
+    pub target_level_dbfs: i32,
+    pub compression_gain_db: i32,
+    pub enable_limiter: bool,
+}
+
+impl Config {
+    /// Lowers the Rust config into the bindgen `AudioProcessing::Config`,
+    /// keeping the C++ default for any stage left as `None`.
+    pub(crate) fn to_ffi(&self) -> ffi::webrtc::AudioProcessing_Config {
+        let mut config = ffi::webrtc::AudioProcessing_Config::default();
+
+        if let Some(hpf) = &self.high_pass_filter {
+            config.high_pass_filter.enabled = hpf.enabled;
+        }
+        if let Some(aec) = &self.echo_canceller {
+            config.echo_canceller.enabled = aec.enabled;
+            config.echo_canceller.mobile_mode = aec.mobile_mode;
+        }
+        if let Some(ns) = &self.noise_suppression {
+            config.noise_suppression.enabled = ns.enabled;
+            config.noise_suppression.level = match ns.level {
+                NoiseSuppressionLevel::Low => {
+                    ffi::webrtc::AudioProcessing_Config_NoiseSuppression_Level_kLow
+                },
+                NoiseSuppressionLevel::Moderate => {
+                    ffi::webrtc::AudioProcessing_Config_NoiseSuppression_Level_kModerate
+                },
+                NoiseSuppressionLevel::High => {
+                    ffi::webrtc::AudioProcessing_Config_NoiseSuppression_Level_kHigh
+                },
+                NoiseSuppressionLevel::VeryHigh => {
+                    ffi::webrtc::AudioProcessing_Config_NoiseSuppression_Level_kVeryHigh
+                },
+            };
+        }
+        if let Some(agc) = &self.gain_controller {
+            config.gain_controller1.enabled = agc.enabled;
+            config.gain_controller1.target_level_dbfs = agc.target_level_dbfs;
+            config.gain_controller1.compression_gain_db = agc.compression_gain_db;
+            config.gain_controller1.enable_limiter = agc.enable_limiter;
+        }
+
+        config
+    }
+}
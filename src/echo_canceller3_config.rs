@@ -0,0 +1,420 @@
+//! The Rust mirror of `webrtc::EchoCanceller3Config`, the tuning struct AEC3
+//! reads at construction time. Field names and defaults follow
+//! `api/audio/echo_canceller3_config.h`; only the sub-structs Chrome exposes for
+//! field-trial tuning are surfaced here.
+//!
+//! [`EchoCanceller3Config::to_json`] / [`EchoCanceller3Config::from_json`] round
+//! trip through WebRTC's own canonical (de)serializer rather than serde, so
+//! configs captured from browser flags or `aecdump`s load unchanged.
+
+use std::ffi::{CStr, CString};
+
+use webrtc_audio_processing_sys as ffi;
+
+use crate::Error;
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// Top-level AEC3 tuning. `EchoCanceller3Config::default()` matches the C++
+/// default-constructed config.
+///
+/// The public fields are the subset of tuning the Rust API models directly and
+/// are what the JSON5 example deserializes into. A config produced by
+/// [`from_json`](Self::from_json) additionally retains the *full* parsed WebRTC
+/// config as a hidden base, so fields outside the modeled subset survive a
+/// `from_json` → [`Processor::with_aec3_config`](crate::Processor::with_aec3_config)
+/// round trip unchanged.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct EchoCanceller3Config {
+    pub delay: Delay,
+    pub filter: Filter,
+    pub erle: Erle,
+    pub ep_strength: EpStrength,
+    pub echo_audibility: EchoAudibility,
+    pub render_levels: RenderLevels,
+    pub echo_model: EchoModel,
+    pub suppressor: Suppressor,
+    // Full parsed WebRTC config, used verbatim as the base in `to_ffi` so that
+    // fields outside the modeled subset are not dropped. `None` for configs
+    // built from defaults or deserialized from JSON5, where the modeled fields
+    // overlaid on the C++ defaults are authoritative.
+    #[cfg_attr(feature = "derive_serde", serde(skip))]
+    base: Option<Box<ffi::webrtc::EchoCanceller3Config>>,
+}
+
+// `base` is an implementation detail carrying unmodeled overrides; equality is
+// defined over the modeled, user-visible fields only.
+impl PartialEq for EchoCanceller3Config {
+    fn eq(&self, other: &Self) -> bool {
+        self.delay == other.delay
+            && self.filter == other.filter
+            && self.erle == other.erle
+            && self.ep_strength == other.ep_strength
+            && self.echo_audibility == other.echo_audibility
+            && self.render_levels == other.render_levels
+            && self.echo_model == other.echo_model
+            && self.suppressor == other.suppressor
+    }
+}
+
+/// Delay estimation and alignment.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Delay {
+    pub default_delay: usize,
+    pub down_sampling_factor: usize,
+    pub num_filters: usize,
+}
+
+/// Coefficients of the refined (fast-adapting, main) adaptive filter.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct RefinedConfiguration {
+    pub length_blocks: usize,
+    pub leakage_converged: f32,
+    pub leakage_diverged: f32,
+    pub error_floor: f32,
+    pub error_ceil: f32,
+    pub noise_gate: f32,
+}
+
+/// Coefficients of the coarse (robust, shadow) adaptive filter. It has no
+/// leakage terms; instead it adapts at a fixed `rate`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct CoarseConfiguration {
+    pub length_blocks: usize,
+    pub rate: f32,
+    pub noise_gate: f32,
+}
+
+/// The adaptive filter bank, carrying the refined and coarse filters.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Filter {
+    pub refined: RefinedConfiguration,
+    pub coarse: CoarseConfiguration,
+    pub export_linear_aec_output: bool,
+}
+
+/// Echo return loss enhancement bounds.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Erle {
+    pub min: f32,
+    pub max_l: f32,
+    pub max_h: f32,
+}
+
+/// Echo-path gain strength prior.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct EpStrength {
+    pub default_gain: f32,
+    pub default_len: f32,
+    pub echo_can_saturate: bool,
+    pub bounded_erl: bool,
+}
+
+/// Thresholds governing when residual echo is considered audible.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct EchoAudibility {
+    pub low_render_limit: f32,
+    pub normal_render_limit: f32,
+    pub floor_power: f32,
+}
+
+/// Render-signal activity thresholds.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct RenderLevels {
+    pub active_render_limit: f32,
+    pub poor_excitation_render_limit: f32,
+}
+
+/// Echo power estimator model.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct EchoModel {
+    pub noise_floor_hold: usize,
+    pub min_noise_floor_power: f32,
+    pub stationary_gate_slope: f32,
+}
+
+/// Suppression mask tuning for one frequency band. [`Suppressor`] carries one
+/// for the low-frequency and one for the high-frequency band.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct MaskingThresholds {
+    pub enr_transparent: f32,
+    pub enr_suppress: f32,
+    pub emr_transparent: f32,
+}
+
+/// Residual-echo suppressor tuning.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Suppressor {
+    pub nearend_average_blocks: usize,
+    pub mask_lf: MaskingThresholds,
+    pub mask_hf: MaskingThresholds,
+}
+
+impl EchoCanceller3Config {
+    /// Serializes to the canonical nested AEC3 JSON used by Chrome field trials.
+    pub fn to_json(&self) -> String {
+        let ffi_config = self.to_ffi();
+        unsafe {
+            let raw = ffi::aec3_config_to_json(&ffi_config);
+            let json = CStr::from_ptr(raw).to_string_lossy().into_owned();
+            ffi::string_free(raw);
+            json
+        }
+    }
+
+    /// Parses a canonical AEC3 JSON document, applying it as a partial override
+    /// on top of the C++ defaults: keys absent from `json` keep their default
+    /// value instead of being zeroed.
+    ///
+    /// The input must be in WebRTC's canonical form, i.e. wrapped in a top-level
+    /// `"aec3"` object (`{ "aec3": { ... } }`), matching what [`to_json`](Self::to_json)
+    /// emits and what `aecdump`s / browser field-trial flags carry. The full
+    /// parsed config — including fields the Rust API does not model — is retained
+    /// so it is applied unchanged when the config is handed to the processor.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let c_json = CString::new(json).map_err(|_| Error::Json)?;
+        // Seed with the C++ defaults so keys missing from the document retain
+        // their default value after the overrides are applied.
+        let mut ffi_config = unsafe { ffi::aec3_config_default() };
+        let parsed =
+            unsafe { ffi::aec3_config_from_json(c_json.as_ptr(), &mut ffi_config) };
+        if !parsed {
+            return Err(Error::Json);
+        }
+        let mut config = Self::from_ffi(&ffi_config);
+        // Keep the full parsed config so unmodeled overrides survive `to_ffi`.
+        config.base = Some(Box::new(ffi_config));
+        Ok(config)
+    }
+
+    pub(crate) fn to_ffi(&self) -> ffi::webrtc::EchoCanceller3Config {
+        // Start from the full parsed config when we have one (so unmodeled
+        // fields are preserved), otherwise from the C++ defaults.
+        let mut c = match &self.base {
+            Some(base) => (**base).clone(),
+            None => unsafe { ffi::aec3_config_default() },
+        };
+
+        c.delay.default_delay = self.delay.default_delay;
+        c.delay.down_sampling_factor = self.delay.down_sampling_factor;
+        c.delay.num_filters = self.delay.num_filters;
+
+        copy_refined_to_ffi(&self.filter.refined, &mut c.filter.refined);
+        copy_coarse_to_ffi(&self.filter.coarse, &mut c.filter.coarse);
+        c.filter.export_linear_aec_output = self.filter.export_linear_aec_output;
+
+        c.erle.min = self.erle.min;
+        c.erle.max_l = self.erle.max_l;
+        c.erle.max_h = self.erle.max_h;
+
+        c.ep_strength.default_gain = self.ep_strength.default_gain;
+        c.ep_strength.default_len = self.ep_strength.default_len;
+        c.ep_strength.echo_can_saturate = self.ep_strength.echo_can_saturate;
+        c.ep_strength.bounded_erl = self.ep_strength.bounded_erl;
+
+        c.echo_audibility.low_render_limit = self.echo_audibility.low_render_limit;
+        c.echo_audibility.normal_render_limit = self.echo_audibility.normal_render_limit;
+        c.echo_audibility.floor_power = self.echo_audibility.floor_power;
+
+        c.render_levels.active_render_limit = self.render_levels.active_render_limit;
+        c.render_levels.poor_excitation_render_limit =
+            self.render_levels.poor_excitation_render_limit;
+
+        c.echo_model.noise_floor_hold = self.echo_model.noise_floor_hold;
+        c.echo_model.min_noise_floor_power = self.echo_model.min_noise_floor_power;
+        c.echo_model.stationary_gate_slope = self.echo_model.stationary_gate_slope;
+
+        c.suppressor.nearend_average_blocks = self.suppressor.nearend_average_blocks;
+        copy_mask_to_ffi(&self.suppressor.mask_lf, &mut c.suppressor.mask_lf);
+        copy_mask_to_ffi(&self.suppressor.mask_hf, &mut c.suppressor.mask_hf);
+
+        c
+    }
+
+    pub(crate) fn from_ffi(c: &ffi::webrtc::EchoCanceller3Config) -> Self {
+        Self {
+            delay: Delay {
+                default_delay: c.delay.default_delay,
+                down_sampling_factor: c.delay.down_sampling_factor,
+                num_filters: c.delay.num_filters,
+            },
+            filter: Filter {
+                refined: refined_from_ffi(&c.filter.refined),
+                coarse: coarse_from_ffi(&c.filter.coarse),
+                export_linear_aec_output: c.filter.export_linear_aec_output,
+            },
+            erle: Erle { min: c.erle.min, max_l: c.erle.max_l, max_h: c.erle.max_h },
+            ep_strength: EpStrength {
+                default_gain: c.ep_strength.default_gain,
+                default_len: c.ep_strength.default_len,
+                echo_can_saturate: c.ep_strength.echo_can_saturate,
+                bounded_erl: c.ep_strength.bounded_erl,
+            },
+            echo_audibility: EchoAudibility {
+                low_render_limit: c.echo_audibility.low_render_limit,
+                normal_render_limit: c.echo_audibility.normal_render_limit,
+                floor_power: c.echo_audibility.floor_power,
+            },
+            render_levels: RenderLevels {
+                active_render_limit: c.render_levels.active_render_limit,
+                poor_excitation_render_limit: c.render_levels.poor_excitation_render_limit,
+            },
+            echo_model: EchoModel {
+                noise_floor_hold: c.echo_model.noise_floor_hold,
+                min_noise_floor_power: c.echo_model.min_noise_floor_power,
+                stationary_gate_slope: c.echo_model.stationary_gate_slope,
+            },
+            suppressor: Suppressor {
+                nearend_average_blocks: c.suppressor.nearend_average_blocks,
+                mask_lf: mask_from_ffi(&c.suppressor.mask_lf),
+                mask_hf: mask_from_ffi(&c.suppressor.mask_hf),
+            },
+            base: None,
+        }
+    }
+}
+
+fn copy_refined_to_ffi(
+    src: &RefinedConfiguration,
+    dst: &mut ffi::webrtc::EchoCanceller3Config_Filter_RefinedConfiguration,
+) {
+    dst.length_blocks = src.length_blocks;
+    dst.leakage_converged = src.leakage_converged;
+    dst.leakage_diverged = src.leakage_diverged;
+    dst.error_floor = src.error_floor;
+    dst.error_ceil = src.error_ceil;
+    dst.noise_gate = src.noise_gate;
+}
+
+fn refined_from_ffi(
+    src: &ffi::webrtc::EchoCanceller3Config_Filter_RefinedConfiguration,
+) -> RefinedConfiguration {
+    RefinedConfiguration {
+        length_blocks: src.length_blocks,
+        leakage_converged: src.leakage_converged,
+        leakage_diverged: src.leakage_diverged,
+        error_floor: src.error_floor,
+        error_ceil: src.error_ceil,
+        noise_gate: src.noise_gate,
+    }
+}
+
+fn copy_coarse_to_ffi(
+    src: &CoarseConfiguration,
+    dst: &mut ffi::webrtc::EchoCanceller3Config_Filter_CoarseConfiguration,
+) {
+    dst.length_blocks = src.length_blocks;
+    dst.rate = src.rate;
+    dst.noise_gate = src.noise_gate;
+}
+
+fn coarse_from_ffi(
+    src: &ffi::webrtc::EchoCanceller3Config_Filter_CoarseConfiguration,
+) -> CoarseConfiguration {
+    CoarseConfiguration {
+        length_blocks: src.length_blocks,
+        rate: src.rate,
+        noise_gate: src.noise_gate,
+    }
+}
+
+fn copy_mask_to_ffi(
+    src: &MaskingThresholds,
+    dst: &mut ffi::webrtc::EchoCanceller3Config_Suppressor_MaskingThresholds,
+) {
+    dst.enr_transparent = src.enr_transparent;
+    dst.enr_suppress = src.enr_suppress;
+    dst.emr_transparent = src.emr_transparent;
+}
+
+fn mask_from_ffi(
+    src: &ffi::webrtc::EchoCanceller3Config_Suppressor_MaskingThresholds,
+) -> MaskingThresholds {
+    MaskingThresholds {
+        enr_transparent: src.enr_transparent,
+        enr_suppress: src.enr_suppress,
+        emr_transparent: src.emr_transparent,
+    }
+}
+
+impl Default for EchoCanceller3Config {
+    fn default() -> Self {
+        // Mirrors the C++ default constructor of `EchoCanceller3Config`.
+        let defaults = unsafe { ffi::aec3_config_default() };
+        Self::from_ffi(&defaults)
+    }
+}
+
+// The sub-structs carry a container-level `serde(default)` for partial overrides
+// in the JSON5 example, which requires `Default`. Deriving would zero the
+// fields; instead take each sub-struct from the C++ default config so the
+// defaults match upstream exactly.
+macro_rules! sub_struct_default {
+    ($($ty:ident => $($field:tt).+),+ $(,)?) => {
+        $(
+            impl Default for $ty {
+                fn default() -> Self {
+                    EchoCanceller3Config::default().$($field).+
+                }
+            }
+        )+
+    };
+}
+
+sub_struct_default! {
+    Delay => delay,
+    RefinedConfiguration => filter.refined,
+    CoarseConfiguration => filter.coarse,
+    Filter => filter,
+    Erle => erle,
+    EpStrength => ep_strength,
+    EchoAudibility => echo_audibility,
+    RenderLevels => render_levels,
+    EchoModel => echo_model,
+    MaskingThresholds => suppressor.mask_lf,
+    Suppressor => suppressor,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trips_through_default() {
+        // Compare the serialized bytes rather than the parsed structs so the
+        // check does not hinge on exact f32 text round-tripping.
+        let json = EchoCanceller3Config::default().to_json();
+        let parsed = EchoCanceller3Config::from_json(&json).unwrap();
+        assert_eq!(json, parsed.to_json());
+    }
+
+    #[test]
+    fn from_json_keeps_defaults_for_absent_keys() {
+        // Canonical documents are wrapped in a top-level "aec3" object. Override
+        // a single leaf; every other modeled field must stay at its default.
+        let json = r#"{ "aec3": { "delay": { "default_delay": 7 } } }"#;
+        let parsed = EchoCanceller3Config::from_json(json).unwrap();
+
+        let mut expected = EchoCanceller3Config::default();
+        expected.delay.default_delay = 7;
+        assert_eq!(parsed, expected);
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_input() {
+        assert!(matches!(EchoCanceller3Config::from_json("not json"), Err(Error::Json)));
+    }
+}
@@ -0,0 +1,182 @@
+//! A safe wrapper around Google's [`webrtc-audio-processing`] library, exposing
+//! the acoustic echo cancellation (AEC3), noise suppression and gain control
+//! pipeline that powers Chrome's real-time audio stack.
+//!
+//! [`webrtc-audio-processing`]: https://github.com/jacksongoode/webrtc-audio-processing
+
+mod config;
+mod echo_canceller3_config;
+#[cfg(feature = "offline")]
+mod offline;
+mod runtime_setting;
+mod stats;
+
+pub use config::*;
+pub use echo_canceller3_config::*;
+#[cfg(feature = "offline")]
+pub use offline::*;
+pub use runtime_setting::*;
+pub use stats::*;
+
+use std::{error, fmt, sync::Arc};
+use webrtc_audio_processing_sys as ffi;
+
+/// Represents a failure inside `webrtc::AudioProcessing` or its wrapper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A non-success `webrtc::AudioProcessing::Error` code.
+    Code(i32),
+    /// The AEC3 JSON document could not be parsed.
+    Json,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Code(code) => write!(f, "AudioProcessing error code={}", code),
+            Error::Json => write!(f, "failed to parse AEC3 config JSON"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Minimal required setup for the audio processing pipeline. Every frame handed
+/// to the [`Processor`] afterwards must match these channel counts and rate.
+#[derive(Debug, Clone)]
+pub struct InitializationConfig {
+    /// Number of the interleaved channels in the capture (near-end) stream.
+    pub num_capture_channels: usize,
+    /// Number of the interleaved channels in the render (far-end) stream.
+    pub num_render_channels: usize,
+    /// Sample rate of both streams, in Hz. WebRTC processes internally in
+    /// 10 ms blocks, i.e. `sample_rate_hz / 100` samples per channel per frame.
+    pub sample_rate_hz: u32,
+}
+
+/// The `webrtc::AudioProcessing` instance. Cloning a `Processor` yields another
+/// handle onto the *same* underlying pipeline, so configuration and statistics
+/// are shared across clones.
+#[derive(Clone)]
+pub struct Processor {
+    inner: Arc<AudioProcessing>,
+    num_capture_channels: usize,
+    num_render_channels: usize,
+    num_samples_per_frame: usize,
+}
+
+impl Processor {
+    /// Creates a new processor with the default AEC3 tuning.
+    pub fn new(config: &InitializationConfig) -> Result<Self, Error> {
+        Self::with_aec3_config(config, None)
+    }
+
+    /// Creates a new processor, optionally overriding the AEC3 tuning with a
+    /// custom [`EchoCanceller3Config`].
+    pub fn with_aec3_config(
+        config: &InitializationConfig,
+        aec3_config: Option<EchoCanceller3Config>,
+    ) -> Result<Self, Error> {
+        let inner = AudioProcessing::new(config, aec3_config)?;
+        Ok(Self {
+            inner: Arc::new(inner),
+            num_capture_channels: config.num_capture_channels,
+            num_render_channels: config.num_render_channels,
+            num_samples_per_frame: (config.sample_rate_hz / 100) as usize,
+        })
+    }
+
+    /// Processes and modifies the near-end (capture) audio frame in place. The
+    /// slice must hold `num_samples_per_frame * num_capture_channels`
+    /// interleaved samples.
+    pub fn process_capture_frame(&mut self, frame: &mut [f32]) -> Result<(), Error> {
+        assert_eq!(frame.len(), self.num_samples_per_frame * self.num_capture_channels);
+        let code = unsafe { ffi::process_capture_frame(self.inner.as_raw(), frame.as_mut_ptr()) };
+        Error::from_code(code)
+    }
+
+    /// Processes and modifies the far-end (render) audio frame in place, feeding
+    /// AEC3 the reference signal it subtracts from the capture stream.
+    pub fn process_render_frame(&mut self, frame: &mut [f32]) -> Result<(), Error> {
+        assert_eq!(frame.len(), self.num_samples_per_frame * self.num_render_channels);
+        let code = unsafe { ffi::process_render_frame(self.inner.as_raw(), frame.as_mut_ptr()) };
+        Error::from_code(code)
+    }
+
+    /// Applies runtime configuration changes to the pipeline.
+    pub fn set_config(&mut self, config: Config) {
+        unsafe { ffi::set_config(self.inner.as_raw(), &config.to_ffi()) };
+    }
+
+    /// Returns the diagnostic metrics WebRTC computed for the most recently
+    /// processed capture frame (echo return loss, estimated delay, residual
+    /// echo likelihood, voice activity).
+    pub fn get_stats(&self) -> Stats {
+        unsafe { ffi::get_stats(self.inner.as_raw()) }.into()
+    }
+
+    /// Enqueues a lock-free [`RuntimeSetting`]. Unlike [`set_config`], this does
+    /// not reinitialize the pipeline, so it is safe to call from inside the
+    /// real-time audio callback; the change applies on the next capture frame.
+    ///
+    /// [`set_config`]: Processor::set_config
+    pub fn set_runtime_setting(&self, setting: RuntimeSetting) {
+        setting.apply(self.inner.as_raw());
+    }
+}
+
+impl Error {
+    pub(crate) fn from_code(code: i32) -> Result<(), Error> {
+        if unsafe { ffi::is_success(code) } {
+            Ok(())
+        } else {
+            Err(Error::Code(code))
+        }
+    }
+}
+
+/// Owns the raw `webrtc::AudioProcessing` handle and frees it on drop. Wrapped
+/// in an `Arc` by [`Processor`] so the pipeline outlives any single handle.
+struct AudioProcessing {
+    inner: *mut ffi::AudioProcessing,
+}
+
+impl AudioProcessing {
+    fn new(
+        config: &InitializationConfig,
+        aec3_config: Option<EchoCanceller3Config>,
+    ) -> Result<Self, Error> {
+        let aec3 = aec3_config.map(|c| c.to_ffi());
+        let aec3_ptr = aec3.as_ref().map_or(std::ptr::null(), |c| c as *const _);
+
+        let mut code = 0;
+        let inner = unsafe {
+            ffi::audio_processing_create(
+                config.num_capture_channels as i32,
+                config.num_render_channels as i32,
+                config.sample_rate_hz as i32,
+                aec3_ptr,
+                &mut code,
+            )
+        };
+        if inner.is_null() {
+            return Err(Error::Code(code));
+        }
+        Ok(Self { inner })
+    }
+
+    fn as_raw(&self) -> *mut ffi::AudioProcessing {
+        self.inner
+    }
+}
+
+impl Drop for AudioProcessing {
+    fn drop(&mut self) {
+        unsafe { ffi::audio_processing_delete(self.inner) };
+    }
+}
+
+// The underlying `webrtc::AudioProcessing` is internally synchronized for
+// concurrent capture/render callers, matching WebRTC's own threading model.
+unsafe impl Send for AudioProcessing {}
+unsafe impl Sync for AudioProcessing {}
@@ -0,0 +1,305 @@
+//! Offline, file-to-file processing so the pipeline can be evaluated against
+//! recorded near-/far-end pairs without a live audio device.
+//!
+//! [`process_file`] decodes the capture and render tracks with Symphonia,
+//! resamples each to the processor's fixed 48 kHz rate, chops them into aligned
+//! 10 ms frames — feeding every render frame before its paired capture frame so
+//! AEC3 sees the reference first — and writes the cleaned capture to a WAV.
+
+use std::{fmt, fs::File, io, path::Path};
+
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::DecoderOptions,
+    errors::Error as SymphoniaError,
+    formats::FormatOptions,
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+};
+
+use crate::{Config, Error as ProcessingError, InitializationConfig, Processor};
+
+/// Sample rate the [`Processor`] mandates for both streams.
+const SAMPLE_RATE_HZ: u32 = 48_000;
+/// Samples per channel in a 10 ms frame at [`SAMPLE_RATE_HZ`].
+const FRAME_SIZE: usize = (SAMPLE_RATE_HZ / 100) as usize;
+/// Input block the resampler consumes per call.
+const RESAMPLE_CHUNK: usize = 1_024;
+
+/// A failure raised while running the offline helper.
+#[derive(Debug)]
+pub enum OfflineError {
+    /// An I/O error opening or writing a file.
+    Io(io::Error),
+    /// Symphonia could not probe or decode an input file.
+    Decode(SymphoniaError),
+    /// The input container held no decodable audio track, or no sample rate.
+    NoTrack,
+    /// The resampler could not be constructed or run.
+    Resample(String),
+    /// The WAV writer failed.
+    Wav(hound::Error),
+    /// The underlying `AudioProcessing` pipeline returned an error.
+    Processing(ProcessingError),
+}
+
+impl fmt::Display for OfflineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OfflineError::Io(e) => write!(f, "io error: {e}"),
+            OfflineError::Decode(e) => write!(f, "decode error: {e}"),
+            OfflineError::NoTrack => write!(f, "input file has no decodable audio track"),
+            OfflineError::Resample(msg) => write!(f, "resample error: {msg}"),
+            OfflineError::Wav(e) => write!(f, "wav error: {e}"),
+            OfflineError::Processing(e) => write!(f, "processing error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for OfflineError {}
+
+impl From<io::Error> for OfflineError {
+    fn from(e: io::Error) -> Self {
+        OfflineError::Io(e)
+    }
+}
+impl From<SymphoniaError> for OfflineError {
+    fn from(e: SymphoniaError) -> Self {
+        OfflineError::Decode(e)
+    }
+}
+impl From<rubato::ResampleError> for OfflineError {
+    fn from(e: rubato::ResampleError) -> Self {
+        OfflineError::Resample(e.to_string())
+    }
+}
+impl From<rubato::ResamplerConstructionError> for OfflineError {
+    fn from(e: rubato::ResamplerConstructionError) -> Self {
+        OfflineError::Resample(e.to_string())
+    }
+}
+impl From<hound::Error> for OfflineError {
+    fn from(e: hound::Error) -> Self {
+        OfflineError::Wav(e)
+    }
+}
+impl From<ProcessingError> for OfflineError {
+    fn from(e: ProcessingError) -> Self {
+        OfflineError::Processing(e)
+    }
+}
+
+/// Runs `config` over a recorded capture/render pair and writes the cleaned
+/// capture to `out_path` as a 48 kHz mono WAV.
+///
+/// Both inputs may be any format and sample rate Symphonia can decode; they are
+/// downmixed to mono, resampled to 48 kHz and aligned frame-for-frame. A
+/// trailing partial frame is zero-padded so the whole capture is processed.
+pub fn process_file(
+    capture_path: impl AsRef<Path>,
+    render_path: impl AsRef<Path>,
+    out_path: impl AsRef<Path>,
+    config: &Config,
+) -> Result<(), OfflineError> {
+    let (capture, capture_rate) = decode_to_mono(capture_path.as_ref())?;
+    let (render, render_rate) = decode_to_mono(render_path.as_ref())?;
+
+    let capture = resample_to_processor_rate(&capture, capture_rate)?;
+    let render = resample_to_processor_rate(&render, render_rate)?;
+
+    let mut processor = Processor::new(&InitializationConfig {
+        num_capture_channels: 1,
+        num_render_channels: 1,
+        sample_rate_hz: SAMPLE_RATE_HZ,
+    })?;
+    processor.set_config(config.clone());
+
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE_HZ,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(out_path.as_ref(), spec)?;
+
+    // Number of frames spanning the longer of the two streams; the shorter one
+    // is implicitly zero-padded by `frame_at`.
+    let num_frames =
+        (capture.len().max(render.len()) + FRAME_SIZE - 1) / FRAME_SIZE;
+
+    let mut render_frame = [0.0f32; FRAME_SIZE];
+    let mut capture_frame = [0.0f32; FRAME_SIZE];
+    for i in 0..num_frames {
+        copy_frame(&render, i, &mut render_frame);
+        copy_frame(&capture, i, &mut capture_frame);
+
+        // Feed the reference (render) frame first so AEC3 is aligned before it
+        // sees the matching near-end frame.
+        processor.process_render_frame(&mut render_frame)?;
+        processor.process_capture_frame(&mut capture_frame)?;
+
+        for &sample in &capture_frame {
+            writer.write_sample(sample)?;
+        }
+    }
+
+    writer.finalize()?;
+    Ok(())
+}
+
+/// Copies the `index`-th 10 ms frame out of `samples`, zero-padding past the
+/// end of the slice.
+fn copy_frame(samples: &[f32], index: usize, frame: &mut [f32; FRAME_SIZE]) {
+    let start = index * FRAME_SIZE;
+    for (offset, out) in frame.iter_mut().enumerate() {
+        *out = samples.get(start + offset).copied().unwrap_or(0.0);
+    }
+}
+
+/// Decodes an arbitrary audio file to mono `f32`, returning the samples and
+/// their source sample rate.
+fn decode_to_mono(path: &Path) -> Result<(Vec<f32>, u32), OfflineError> {
+    let file = File::open(path)?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        stream,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+    let mut format = probed.format;
+
+    let track = format.default_track().ok_or(OfflineError::NoTrack)?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.ok_or(OfflineError::NoTrack)?;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut samples = Vec::new();
+    let mut sample_buf: Option<SampleBuffer<f32>> = None;
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // A clean end-of-stream arrives as an unexpected-EOF io error.
+            Err(SymphoniaError::IoError(e))
+                if e.kind() == io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            },
+            Err(e) => return Err(e.into()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = decoder.decode(&packet)?;
+        let spec = *decoded.spec();
+        let channels = spec.channels.count().max(1);
+        let buf = sample_buf
+            .get_or_insert_with(|| SampleBuffer::new(decoded.capacity() as u64, spec));
+        buf.copy_interleaved_ref(decoded);
+
+        for frame in buf.samples().chunks(channels) {
+            let mono = frame.iter().sum::<f32>() / channels as f32;
+            samples.push(mono);
+        }
+    }
+
+    Ok((samples, sample_rate))
+}
+
+/// Resamples mono `input` from `from_rate` to [`SAMPLE_RATE_HZ`], streaming it
+/// through the resampler in fixed blocks and zero-padding the trailing block.
+fn resample_to_processor_rate(input: &[f32], from_rate: u32) -> Result<Vec<f32>, OfflineError> {
+    if from_rate == SAMPLE_RATE_HZ {
+        return Ok(input.to_vec());
+    }
+
+    let params = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        interpolation: SincInterpolationType::Linear,
+        oversampling_factor: 256,
+        window: WindowFunction::BlackmanHarris2,
+    };
+    let mut resampler = SincFixedIn::<f32>::new(
+        SAMPLE_RATE_HZ as f64 / from_rate as f64,
+        2.0,
+        params,
+        RESAMPLE_CHUNK,
+        1,
+    )?;
+
+    let mut output = Vec::new();
+    let mut block = vec![0.0f32; RESAMPLE_CHUNK];
+    for chunk in input.chunks(RESAMPLE_CHUNK) {
+        block[..chunk.len()].copy_from_slice(chunk);
+        for sample in block.iter_mut().skip(chunk.len()) {
+            *sample = 0.0;
+        }
+        let resampled = resampler.process(&[block.clone()], None)?;
+        output.extend_from_slice(&resampled[0]);
+    }
+
+    // Flush the resampler's internal delay line so the ~sinc_len/2 samples of
+    // latency held back at the end are emitted rather than dropped.
+    let flushed = resampler.process_partial::<Vec<f32>>(None, None)?;
+    output.extend_from_slice(&flushed[0]);
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn write_tone(path: &Path, sample_rate: u32, num_samples: usize) {
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for n in 0..num_samples {
+            let t = n as f32 / sample_rate as f32;
+            writer.write_sample((2.0 * PI * 440.0 * t).sin() * 0.25).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn process_file_resamples_and_aligns_to_frames() {
+        let dir = std::env::temp_dir();
+        let capture = dir.join("wap_offline_capture.wav");
+        let render = dir.join("wap_offline_render.wav");
+        let out = dir.join("wap_offline_out.wav");
+
+        // 16 kHz input forces the resampler onto the processor's 48 kHz path.
+        write_tone(&capture, 16_000, 8_000);
+        write_tone(&render, 16_000, 8_000);
+
+        process_file(&capture, &render, &out, &Config::default()).unwrap();
+
+        let reader = hound::WavReader::open(&out).unwrap();
+        let spec = reader.spec();
+        assert_eq!(spec.sample_rate, SAMPLE_RATE_HZ);
+        assert_eq!(spec.channels, 1);
+        let len = reader.len() as usize;
+        assert!(len > 0);
+        // Output is whole 10 ms frames, with the trailing frame zero-padded.
+        assert_eq!(len % FRAME_SIZE, 0);
+    }
+}
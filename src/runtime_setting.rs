@@ -0,0 +1,56 @@
+//! Lock-free, mid-stream parameter changes applied through
+//! `AudioProcessing::SetRuntimeSetting`.
+
+use webrtc_audio_processing_sys as ffi;
+
+/// A parameter that can be mutated while the pipeline is running, without the
+/// teardown [`set_config`](crate::Processor::set_config) or reconstruction would
+/// force. Each setting is enqueued atomically and takes effect on the next
+/// [`process_capture_frame`](crate::Processor::process_capture_frame).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuntimeSetting {
+    /// Linear gain applied to the capture stream before any processing.
+    CapturePreGain(f32),
+    /// Linear gain applied to the capture stream after processing.
+    CapturePostGain(f32),
+    /// Fixed (compression) gain of the AGC2 capture stage, in dB.
+    CaptureFixedPostGain(f32),
+    /// New playout device volume, on the device's own scale.
+    PlayoutVolumeChange(i32),
+    /// A change of playout audio device, carrying the new device's volume range.
+    PlayoutAudioDeviceChange {
+        /// Opaque identifier of the new playout device.
+        id: i32,
+        /// Maximum volume the device reports.
+        max_volume: i32,
+        /// Minimum volume the device reports.
+        min_volume: i32,
+    },
+}
+
+impl RuntimeSetting {
+    /// Enqueues this setting on `ap` via the matching `SetRuntimeSetting` call.
+    pub(crate) fn apply(self, ap: *mut ffi::AudioProcessing) {
+        unsafe {
+            match self {
+                RuntimeSetting::CapturePreGain(gain) => {
+                    ffi::set_runtime_setting_capture_pre_gain(ap, gain)
+                },
+                RuntimeSetting::CapturePostGain(gain) => {
+                    ffi::set_runtime_setting_capture_post_gain(ap, gain)
+                },
+                RuntimeSetting::CaptureFixedPostGain(gain) => {
+                    ffi::set_runtime_setting_capture_fixed_post_gain(ap, gain)
+                },
+                RuntimeSetting::PlayoutVolumeChange(volume) => {
+                    ffi::set_runtime_setting_playout_volume_change(ap, volume)
+                },
+                RuntimeSetting::PlayoutAudioDeviceChange { id, max_volume, min_volume } => {
+                    ffi::set_runtime_setting_playout_audio_device_change(
+                        ap, id, max_volume, min_volume,
+                    )
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Diagnostic metrics reported by `webrtc::AudioProcessing::GetStatistics()`.
+
+use webrtc_audio_processing_sys as ffi;
+
+/// Statistics snapshot taken after a capture frame. Each field is `None` until
+/// the corresponding WebRTC sub-module has produced an estimate, mirroring the
+/// `absl::optional` types on the C++ side.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    /// Echo return loss, in dB.
+    pub echo_return_loss: Option<f64>,
+    /// Echo return loss enhancement, in dB.
+    pub echo_return_loss_enhancement: Option<f64>,
+    /// Estimated delay between the render and capture streams, in ms.
+    pub delay_ms: Option<f64>,
+    /// Median of the estimated delay over the measurement window, in ms.
+    pub delay_median_ms: Option<f64>,
+    /// Probability that the capture stream still contains residual echo.
+    pub residual_echo_likelihood: Option<f64>,
+    /// Maximum residual-echo likelihood observed over the recent window.
+    pub residual_echo_likelihood_recent_max: Option<f64>,
+    /// Whether voice activity was detected in the capture stream.
+    pub voice_detected: Option<bool>,
+}
+
+impl From<ffi::Stats> for Stats {
+    fn from(s: ffi::Stats) -> Self {
+        Self {
+            echo_return_loss: optional_f64(s.echo_return_loss),
+            echo_return_loss_enhancement: optional_f64(s.echo_return_loss_enhancement),
+            delay_ms: optional_f64(s.delay_ms),
+            delay_median_ms: optional_f64(s.delay_median_ms),
+            residual_echo_likelihood: optional_f64(s.residual_echo_likelihood),
+            residual_echo_likelihood_recent_max: optional_f64(
+                s.residual_echo_likelihood_recent_max,
+            ),
+            voice_detected: optional_bool(s.voice_detected),
+        }
+    }
+}
+
+fn optional_f64(value: ffi::OptionalDouble) -> Option<f64> {
+    value.has_value.then_some(value.value)
+}
+
+fn optional_bool(value: ffi::OptionalBool) -> Option<bool> {
+    value.has_value.then_some(value.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some_f64(value: f64) -> ffi::OptionalDouble {
+        ffi::OptionalDouble { has_value: true, value }
+    }
+
+    fn none_f64() -> ffi::OptionalDouble {
+        ffi::OptionalDouble { has_value: false, value: 0.0 }
+    }
+
+    #[test]
+    fn converts_present_and_absent_fields() {
+        let raw = ffi::Stats {
+            echo_return_loss: some_f64(12.5),
+            echo_return_loss_enhancement: none_f64(),
+            delay_ms: some_f64(40.0),
+            delay_median_ms: none_f64(),
+            residual_echo_likelihood: some_f64(0.25),
+            residual_echo_likelihood_recent_max: none_f64(),
+            voice_detected: ffi::OptionalBool { has_value: true, value: true },
+        };
+
+        let stats = Stats::from(raw);
+        assert_eq!(stats.echo_return_loss, Some(12.5));
+        assert_eq!(stats.echo_return_loss_enhancement, None);
+        assert_eq!(stats.delay_ms, Some(40.0));
+        assert_eq!(stats.delay_median_ms, None);
+        assert_eq!(stats.residual_echo_likelihood, Some(0.25));
+        assert_eq!(stats.residual_echo_likelihood_recent_max, None);
+        assert_eq!(stats.voice_detected, Some(true));
+    }
+}
@@ -50,7 +50,7 @@ mod webrtc {
     }
 }
 
-#[cfg(feature = "bundled")]
+#[cfg(all(feature = "bundled", not(feature = "wasm")))]
 mod webrtc {
     use super::*;
     use std::{path::Path, process::Command};
@@ -145,13 +145,124 @@ mod webrtc {
     }
 }
 
+#[cfg(feature = "wasm")]
+mod webrtc {
+    use super::*;
+    use std::{path::Path, process::Command};
+
+    const BUNDLED_SOURCE_PATH: &str = "./webrtc-audio-processing";
+    const BUNDLED_SOURCE_PATH_ABSEIL: &str = "./abseil-cpp";
+
+    // Runs `emcmake`/`emconfigure`/`emmake` from the active Emscripten SDK,
+    // pinning the toolchain so the wrapped build targets wasm32.
+    fn emscripten(wrapper: &str, program: &str) -> Command {
+        let mut command = Command::new(wrapper);
+        command.arg(program);
+        command
+    }
+
+    // Writes a meson cross file describing the Emscripten toolchain. `emconfigure`
+    // only exports environment variables that meson ignores for compiler
+    // selection, so meson needs an explicit `--cross-file` to target wasm32.
+    fn write_cross_file(path: &Path) -> Result<(), Error> {
+        std::fs::write(
+            path,
+            "[binaries]\n\
+             c = 'emcc'\n\
+             cpp = 'em++'\n\
+             ar = 'emar'\n\
+             \n\
+             [host_machine]\n\
+             system = 'emscripten'\n\
+             cpu_family = 'wasm32'\n\
+             cpu = 'wasm32'\n\
+             endian = 'little'\n",
+        )?;
+        Ok(())
+    }
+
+    pub(super) fn get_build_paths() -> Result<(Vec<PathBuf>, Vec<PathBuf>), Error> {
+        let build_dir = out_dir();
+        let install_dir = out_dir();
+
+        // Build abseil-cpp through the Emscripten CMake wrapper and install it
+        // into the shared prefix so its headers/archives join the include/lib
+        // paths below.
+        let abseil_build_dir = build_dir.join("abseil-cpp");
+        std::fs::create_dir_all(&abseil_build_dir)?;
+        let mut cmake = emscripten("emcmake", "cmake");
+        let status = cmake
+            .arg(BUNDLED_SOURCE_PATH_ABSEIL)
+            .args(&["-B", abseil_build_dir.to_str().unwrap()])
+            .arg(format!("-DCMAKE_INSTALL_PREFIX={}", install_dir.display()))
+            .arg("-DCMAKE_CXX_STANDARD=17")
+            .arg("-DCMAKE_BUILD_TYPE=Release")
+            .status()
+            .context("Failed to execute emcmake. Is the Emscripten SDK on PATH?")?;
+        assert!(status.success(), "Command failed: {:?}", &cmake);
+
+        let mut cmake_install = Command::new("cmake");
+        let status = cmake_install
+            .args(&["--build", abseil_build_dir.to_str().unwrap()])
+            .args(&["--target", "install"])
+            .status()
+            .context("Failed to install abseil-cpp.")?;
+        assert!(status.success(), "Command failed: {:?}", &cmake_install);
+
+        // Configure + build webrtc-audio-processing via meson/ninja against the
+        // Emscripten cross file. Static only: wasm has no dynamic linker.
+        let webrtc_src_dir = PathBuf::from("webrtc-audio-processing");
+        let webrtc_build_dir = build_dir.join("webrtc-audio-processing");
+        std::fs::create_dir_all(&webrtc_build_dir)?;
+
+        let cross_file = build_dir.join("emscripten-cross.txt");
+        write_cross_file(&cross_file)?;
+
+        let mut meson = emscripten("emconfigure", "meson");
+        let status = meson
+            .args(&["--prefix", install_dir.to_str().unwrap()])
+            .args(&["--cross-file", cross_file.to_str().unwrap()])
+            .arg("-Ddefault_library=static")
+            .arg(&webrtc_src_dir)
+            .arg(&webrtc_build_dir)
+            .status()
+            .context("Failed to execute meson under emconfigure.")?;
+        assert!(status.success(), "Command failed: {:?}", &meson);
+
+        let mut ninja = emscripten("emmake", "ninja");
+        let status = ninja
+            .args(&["-C", webrtc_build_dir.to_str().unwrap()])
+            .arg("install")
+            .status()
+            .context("Failed to execute ninja under emmake.")?;
+        assert!(status.success(), "Command failed: {:?}", &ninja);
+
+        let include_paths = vec![
+            install_dir.join("include/webrtc-audio-processing-2"),
+            install_dir.join("include"),
+            webrtc_src_dir.clone(),
+            webrtc_src_dir.join("webrtc"),
+        ];
+        let lib_paths = vec![install_dir.join("lib")];
+
+        Ok((include_paths, lib_paths))
+    }
+
+    pub(super) fn build_if_necessary() -> Result<(), Error> {
+        Ok(())
+    }
+}
+
 fn main() -> Result<(), Error> {
     let (include_dirs, lib_dirs) = webrtc::get_build_paths()?;
 
-    // Build wrapper
+    // Build wrapper. `echo_canceller3_config_json.cc` carries the canonical
+    // AEC3 (de)serializer (`Aec3ConfigToJsonString` / `Aec3ConfigFromJsonString`)
+    // that the upstream meson build omits, so compile it in alongside the shim.
     cc::Build::new()
         .cpp(true)
         .file("src/wrapper.cpp")
+        .file("webrtc-audio-processing/api/audio/echo_canceller3_config_json.cc")
         .flag("-std=c++17")
         .flag("-Wno-unused-parameter")
         .includes(&include_dirs)
@@ -182,10 +293,10 @@ fn main() -> Result<(), Error> {
         .allowlist_type("webrtc::AudioProcessing_Error")
         .allowlist_type("webrtc::AudioProcessing_Config")
         .allowlist_type("webrtc::AudioProcessing_RealtimeSetting")
+        .allowlist_type("webrtc::EchoCanceller3Config")
         .allowlist_type("webrtc::StreamConfig")
         .allowlist_type("webrtc::ProcessingConfig")
         .allowlist_function("webrtc_audio_processing_wrapper::.*")
-        .blocklist_item("webrtc::AudioProcessing_Config_ToString")
         .opaque_type("std::.*")
         .derive_debug(true)
         .derive_default(true);
@@ -199,13 +310,17 @@ fn main() -> Result<(), Error> {
         println!("cargo:rustc-link-search=native={}", dir.display());
     }
 
-    if cfg!(feature = "bundled") {
+    // wasm has no dynamic linker, so always link statically there; the Emscripten
+    // toolchain produces the archive from the meson/ninja build above.
+    if cfg!(any(feature = "bundled", feature = "wasm")) {
         println!("cargo:rustc-link-lib=static=webrtc-audio-processing-2");
     } else {
         println!("cargo:rustc-link-lib=dylib=webrtc-audio-processing-2");
     }
 
-    if cfg!(target_os = "macos") {
+    // CoreFoundation is a host macOS framework and must not be linked into a
+    // wasm32-unknown-emscripten build.
+    if cfg!(target_os = "macos") && !cfg!(feature = "wasm") {
         println!("cargo:rustc-link-lib=framework=CoreFoundation");
     }
 
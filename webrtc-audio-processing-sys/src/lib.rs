@@ -0,0 +1,14 @@
+//! Raw bindings to the `webrtc-audio-processing` library and the thin C++
+//! shim in `wrapper.cpp`. The high-level, safe API lives in the
+//! `webrtc-audio-processing` crate; everything here is `unsafe` to call.
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(clippy::all)]
+
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+// Types live under `ffi::webrtc::*`; the hand-written shim functions are lifted
+// to the crate root so callers write `ffi::process_capture_frame(..)`.
+pub use root::webrtc;
+pub use root::webrtc_audio_processing_wrapper::*;